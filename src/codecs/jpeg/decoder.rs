@@ -8,6 +8,7 @@ use crate::error::{
 };
 use crate::image::{ImageDecoder, ImageFormat};
 use crate::io::Limits;
+use crate::metadata::Orientation;
 
 type ZuneColorSpace = zune_core::colorspace::ColorSpace;
 
@@ -17,6 +18,20 @@ pub struct JpegDecoder<R> {
     orig_color_space: ZuneColorSpace,
     width: u16,
     height: u16,
+    // The full-resolution dimensions as reported by the backend, kept so that
+    // `scale()` can be re-derived from them rather than from the already-scaled
+    // `width`/`height` (which would be lossy and could overflow).
+    full_width: u16,
+    full_height: u16,
+    // Integer downscaling factor requested via `scale()`: one of 1, 2, 4 or 8.
+    // `width`/`height` above always describe the *scaled* output, before any
+    // orientation transform is applied.
+    scale_factor: u16,
+    // When `true`, the Exif orientation is applied to the decoded pixels.
+    auto_orient: bool,
+    // The orientation to apply when `auto_orient` is set. Determined once from
+    // the Exif metadata when auto-orientation is enabled.
+    orientation: Orientation,
     limits: Limits,
     // For API compatibility with the previous jpeg_decoder wrapper.
     // Can be removed later, which would be an API break.
@@ -43,24 +58,251 @@ impl<R: Read> JpegDecoder<R> {
             orig_color_space,
             width,
             height,
+            full_width: width,
+            full_height: height,
+            scale_factor: 1,
+            auto_orient: false,
+            orientation: Orientation::NoTransforms,
             limits,
             phantom: PhantomData,
         })
     }
 
-    /// Some decoders support scaling the image during decoding,
-    /// but the current backend, `zune-jpeg`, doesn't,
-    /// so this function currently does nothing
-    /// and always returns the original dimensions.
+    /// Requests that the image be downscaled to roughly the given size.
+    ///
+    /// Note that this is **not** a true DCT-domain scaled decode: the current
+    /// backend, `zune-jpeg`, does not expose the DCT coefficients, so the image
+    /// is decoded at full resolution and then reduced by box-averaging in the
+    /// spatial domain. It therefore offers no speed or peak-memory advantage
+    /// over a full decode — it exists only so that `dimensions()` and the
+    /// emitted buffer reflect the requested downscale. If the backend gains
+    /// real scaled-decode support, this can be switched to it transparently.
+    ///
+    /// The smallest of the `1`, `1/2`, `1/4` and `1/8` ratios whose output is
+    /// still at least as large as the requested size is chosen, the decoder's
+    /// reported [`dimensions`](ImageDecoder::dimensions) are updated to match,
+    /// and subsequent calls to [`into_reader`](ImageDecoder::into_reader) or
+    /// [`read_image`](ImageDecoder::read_image) emit the reduced-resolution
+    /// buffer. The actual (possibly larger) scaled dimensions are returned.
     pub fn scale(
         &mut self,
-        _requested_width: u16,
-        _requested_height: u16,
+        requested_width: u16,
+        requested_height: u16,
     ) -> ImageResult<(u16, u16)> {
-        // zune-jpeg doesn't support this yet:
-        // https://github.com/etemesi254/zune-image/issues/103
+        // Always decide against the persisted full-resolution dimensions, so
+        // repeated calls are genuinely idempotent rather than compounding.
+        let full_width = self.full_width;
+        let full_height = self.full_height;
+        let factor =
+            choose_scale_factor(full_width, full_height, requested_width, requested_height);
+        self.scale_factor = factor;
+        self.width = full_width.div_ceil(factor);
+        self.height = full_height.div_ceil(factor);
         Ok((self.width, self.height))
     }
+
+    /// Returns the raw bytes of the Exif APP1 segment, if the image has one.
+    ///
+    /// This is the Exif counterpart to [`icc_profile`](ImageDecoder::icc_profile)
+    /// and returns the block as the backend extracts it: the TIFF structure
+    /// (starting with `II` or `MM`), without the leading `Exif\0\0` identifier,
+    /// so it can be handed to a dedicated Exif parser.
+    pub fn exif_metadata(&mut self) -> Option<Vec<u8>> {
+        let mut decoder = zune_jpeg::JpegDecoder::new(&self.input);
+        decoder.decode_headers().ok()?;
+        decoder.exif().map(|exif| exif.to_vec())
+    }
+
+    /// Parses the orientation tag out of the Exif metadata, if present.
+    ///
+    /// Returns `None` when the image has no Exif segment or no (valid)
+    /// orientation tag; see [`Orientation::from_exif`] for the mapping.
+    pub fn exif_orientation(&mut self) -> Option<Orientation> {
+        let exif = self.exif_metadata()?;
+        Orientation::from_exif(exif_orientation_value(&exif)?)
+    }
+
+    /// Enables or disables automatic orientation correction.
+    ///
+    /// When enabled, the Exif orientation tag is read and the corresponding
+    /// [`Orientation`] transform is applied to the decoded pixels by
+    /// [`read_image`](ImageDecoder::read_image) and
+    /// [`into_reader`](ImageDecoder::into_reader), so callers no longer need to
+    /// read the Exif data and call `apply_orientation` themselves. The reported
+    /// [`dimensions`](ImageDecoder::dimensions) already account for the
+    /// transform. Disabled by default.
+    pub fn set_auto_orient(&mut self, auto_orient: bool) {
+        self.auto_orient = auto_orient;
+        self.orientation = if auto_orient {
+            self.exif_orientation().unwrap_or(Orientation::NoTransforms)
+        } else {
+            Orientation::NoTransforms
+        };
+    }
+}
+
+impl<R: Read> JpegDecoder<R> {
+    /// Accounts for the memory the decode will allocate against `limits`,
+    /// returning [`ImageError::Limits`] if `max_alloc` would be exceeded.
+    ///
+    /// zune-jpeg always decodes at full resolution, so the full-resolution
+    /// buffer is reserved even when `scale()` has reduced the reported
+    /// dimensions. When downscaling, the reduced copy is held alongside the
+    /// full buffer, so it is reserved as well; likewise a rotating
+    /// auto-orientation allocates a second copy of the scaled buffer, which is
+    /// reserved too. Scratch that zune allocates internally is not separately
+    /// tracked here and is bounded instead by the width/height limits forwarded
+    /// to the backend.
+    fn reserve_limits(&self, limits: &mut Limits) -> ImageResult<()> {
+        let color = self.color_type();
+        limits.reserve_buffer(
+            u32::from(self.full_width),
+            u32::from(self.full_height),
+            color,
+        )?;
+        if self.scale_factor != 1 {
+            // The downscaled copy coexists with the full-resolution buffer.
+            limits.reserve_buffer(u32::from(self.width), u32::from(self.height), color)?;
+        }
+        if !self.orientation.applies_in_place() {
+            // A rotating orientation allocates a second copy of the scaled buffer.
+            limits.reserve_buffer(u32::from(self.width), u32::from(self.height), color)?;
+        }
+        Ok(())
+    }
+
+    /// Runs the full decode, applying the requested downscale and orientation,
+    /// and returns the processed pixel buffer. Used whenever the result cannot
+    /// be written straight into the caller's buffer with `decode_into`.
+    fn decode_scaled_oriented(&self) -> ImageResult<Vec<u8>> {
+        let mut limits = self.limits.clone();
+        self.reserve_limits(&mut limits)?;
+        let mut decoder = new_zune_decoder(&self.input, self.orig_color_space, limits);
+        let data = decoder.decode().map_err(ImageError::from_jpeg)?;
+        let channels = self.color_type().channel_count() as usize;
+        let mut data = if self.scale_factor == 1 {
+            data
+        } else {
+            let (full_width, full_height) = decoder.dimensions().unwrap();
+            downscale(&data, full_width, full_height, channels, self.scale_factor)
+        };
+        self.orientation.apply_to_buffer(
+            &mut data,
+            u32::from(self.width),
+            u32::from(self.height),
+            channels,
+        );
+        Ok(data)
+    }
+}
+
+/// Picks the largest downscale factor (smallest output) out of `1`, `2`, `4`
+/// and `8` whose output is still no smaller than the requested size in both
+/// dimensions. Falls back to `1` when the request is larger than the image.
+fn choose_scale_factor(
+    full_width: u16,
+    full_height: u16,
+    requested_width: u16,
+    requested_height: u16,
+) -> u16 {
+    [8u16, 4, 2, 1]
+        .into_iter()
+        .find(|&f| {
+            full_width.div_ceil(f) >= requested_width
+                && full_height.div_ceil(f) >= requested_height
+        })
+        .unwrap_or(1)
+}
+
+/// Reduces a freshly decoded `full_width`×`full_height` buffer to the scaled
+/// dimensions by averaging each `factor`×`factor` source block.
+///
+/// This is a plain spatial-domain box downscale (a low-pass filter followed by
+/// subsampling), not DCT coefficient truncation: the backend only hands us the
+/// fully decoded pixels.
+fn downscale(
+    data: &[u8],
+    full_width: u16,
+    full_height: u16,
+    channels: usize,
+    factor: u16,
+) -> Vec<u8> {
+    let full_width = usize::from(full_width);
+    let full_height = usize::from(full_height);
+    let factor = usize::from(factor);
+    let out_width = full_width.div_ceil(factor);
+    let out_height = full_height.div_ceil(factor);
+    let mut out = vec![0u8; out_width * out_height * channels];
+    for oy in 0..out_height {
+        for ox in 0..out_width {
+            let src_y0 = oy * factor;
+            let src_x0 = ox * factor;
+            for c in 0..channels {
+                let mut sum = 0u32;
+                let mut count = 0u32;
+                for sy in src_y0..(src_y0 + factor).min(full_height) {
+                    for sx in src_x0..(src_x0 + factor).min(full_width) {
+                        sum += u32::from(data[(sy * full_width + sx) * channels + c]);
+                        count += 1;
+                    }
+                }
+                out[(oy * out_width + ox) * channels + c] = (sum / count) as u8;
+            }
+        }
+    }
+    out
+}
+
+/// Reads the orientation tag (`0x0112`) out of a raw Exif APP1 block.
+///
+/// The block is a TIFF header, optionally preceded by the `Exif\0\0`
+/// identifier; only IFD0 is searched, which is where the orientation lives.
+/// Returns `None` on any malformed or missing field rather than erroring, so
+/// that callers can treat "no usable orientation" uniformly.
+fn exif_orientation_value(exif: &[u8]) -> Option<u8> {
+    // Skip the "Exif\0\0" identifier if present; some sources hand over the
+    // bare TIFF block instead.
+    let tiff = match exif.strip_prefix(b"Exif\0\0") {
+        Some(rest) => rest,
+        None => exif,
+    };
+
+    let big_endian = match tiff.get(0..2)? {
+        b"II" => false,
+        b"MM" => true,
+        _ => return None,
+    };
+    let u16_at = |offset: usize| -> Option<u16> {
+        let bytes: [u8; 2] = tiff.get(offset..offset + 2)?.try_into().ok()?;
+        Some(if big_endian {
+            u16::from_be_bytes(bytes)
+        } else {
+            u16::from_le_bytes(bytes)
+        })
+    };
+    let u32_at = |offset: usize| -> Option<u32> {
+        let bytes: [u8; 4] = tiff.get(offset..offset + 4)?.try_into().ok()?;
+        Some(if big_endian {
+            u32::from_be_bytes(bytes)
+        } else {
+            u32::from_le_bytes(bytes)
+        })
+    };
+
+    // Magic number 42 confirms a valid TIFF header.
+    if u16_at(2)? != 42 {
+        return None;
+    }
+    let ifd0 = u32_at(4)? as usize;
+    let entry_count = u16_at(ifd0)? as usize;
+    for i in 0..entry_count {
+        let entry = ifd0 + 2 + i * 12;
+        if u16_at(entry)? == 0x0112 {
+            // Orientation is a single SHORT stored inline in the value field.
+            return Some(u16_at(entry + 8)? as u8);
+        }
+    }
+    None
 }
 
 /// Wrapper struct around a `Cursor<Vec<u8>>`
@@ -83,7 +325,15 @@ impl<'a, R: 'a + Read> ImageDecoder<'a> for JpegDecoder<R> {
     type Reader = JpegReader<R>;
 
     fn dimensions(&self) -> (u32, u32) {
-        (u32::from(self.width), u32::from(self.height))
+        let (width, height) = (u32::from(self.width), u32::from(self.height));
+        // The rotating transforms exchange width and height.
+        match self.orientation {
+            Orientation::Rotate90
+            | Orientation::Rotate270
+            | Orientation::Rotate90FlipH
+            | Orientation::Rotate270FlipH => (height, width),
+            _ => (width, height),
+        }
     }
 
     fn color_type(&self) -> ColorType {
@@ -97,8 +347,7 @@ impl<'a, R: 'a + Read> ImageDecoder<'a> for JpegDecoder<R> {
     }
 
     fn into_reader(self) -> ImageResult<Self::Reader> {
-        let mut decoder = new_zune_decoder(&self.input, self.orig_color_space, self.limits);
-        let data = decoder.decode().map_err(ImageError::from_jpeg)?;
+        let data = self.decode_scaled_oriented()?;
         Ok(JpegReader(Cursor::new(data), PhantomData))
     }
 
@@ -117,8 +366,17 @@ impl<'a, R: 'a + Read> ImageDecoder<'a> for JpegDecoder<R> {
             )));
         }
 
-        let mut decoder = new_zune_decoder(&self.input, self.orig_color_space, self.limits);
-        decoder.decode_into(buf).map_err(ImageError::from_jpeg)?;
+        if self.scale_factor == 1 && self.orientation == Orientation::NoTransforms {
+            // Fast path: the decoder can write straight into the caller's buffer.
+            let mut limits = self.limits.clone();
+            self.reserve_limits(&mut limits)?;
+            let mut decoder = new_zune_decoder(&self.input, self.orig_color_space, limits);
+            decoder.decode_into(buf).map_err(ImageError::from_jpeg)?;
+        } else {
+            // Downscaling and/or orientation need an intermediate buffer.
+            let data = self.decode_scaled_oriented()?;
+            buf.copy_from_slice(&data);
+        }
         Ok(())
     }
 
@@ -186,3 +444,90 @@ impl ImageError {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::metadata::Orientation;
+
+    #[test]
+    fn scale_factor_selection() {
+        // Largest factor whose output still covers the request is chosen.
+        assert_eq!(choose_scale_factor(100, 100, 10, 10), 8);
+        assert_eq!(choose_scale_factor(100, 100, 20, 20), 4);
+        assert_eq!(choose_scale_factor(100, 100, 40, 40), 2);
+        assert_eq!(choose_scale_factor(100, 100, 60, 60), 1);
+        // A request larger than the image falls back to no downscaling.
+        assert_eq!(choose_scale_factor(100, 100, 200, 200), 1);
+        // Both dimensions must still cover the request.
+        assert_eq!(choose_scale_factor(100, 100, 10, 60), 1);
+    }
+
+    #[test]
+    fn downscale_averages_blocks() {
+        // A 4×2 single-channel image reduced by a factor of 2 to 2×1.
+        let data = [0u8, 1, 2, 3, 4, 5, 6, 7];
+        let out = downscale(&data, 4, 2, 1, 2);
+        assert_eq!(out, vec![(0 + 1 + 4 + 5) / 4, (2 + 3 + 6 + 7) / 4]);
+    }
+
+    #[test]
+    fn downscale_handles_ragged_edges() {
+        // A 3×1 row reduced by 2: the trailing block has only one source pixel.
+        let data = [10u8, 20, 30];
+        let out = downscale(&data, 3, 1, 1, 2);
+        assert_eq!(out, vec![(10 + 20) / 2, 30]);
+    }
+
+    /// Builds a minimal Exif APP1 block with a single orientation tag.
+    fn exif_with_orientation(big_endian: bool, value: u16) -> Vec<u8> {
+        let mut block = b"Exif\0\0".to_vec();
+        let (u16_bytes, u32_bytes): (fn(u16) -> [u8; 2], fn(u32) -> [u8; 4]) = if big_endian {
+            (u16::to_be_bytes, u32::to_be_bytes)
+        } else {
+            (u16::to_le_bytes, u32::to_le_bytes)
+        };
+        block.extend_from_slice(if big_endian { b"MM" } else { b"II" });
+        block.extend_from_slice(&u16_bytes(42)); // TIFF magic
+        block.extend_from_slice(&u32_bytes(8)); // offset to IFD0
+        block.extend_from_slice(&u16_bytes(1)); // one entry
+        block.extend_from_slice(&u16_bytes(0x0112)); // orientation tag
+        block.extend_from_slice(&u16_bytes(3)); // type SHORT
+        block.extend_from_slice(&u32_bytes(1)); // count
+        // The value is a SHORT stored inline, left-justified in the 4-byte field.
+        block.extend_from_slice(&u16_bytes(value));
+        block.extend_from_slice(&[0, 0]);
+        block
+    }
+
+    #[test]
+    fn exif_orientation_little_endian() {
+        let block = exif_with_orientation(false, 6);
+        assert_eq!(exif_orientation_value(&block), Some(6));
+        assert_eq!(
+            Orientation::from_exif(exif_orientation_value(&block).unwrap()),
+            Some(Orientation::Rotate90)
+        );
+    }
+
+    #[test]
+    fn exif_orientation_big_endian() {
+        let block = exif_with_orientation(true, 8);
+        assert_eq!(exif_orientation_value(&block), Some(8));
+        assert_eq!(
+            Orientation::from_exif(exif_orientation_value(&block).unwrap()),
+            Some(Orientation::Rotate270)
+        );
+    }
+
+    #[test]
+    fn exif_orientation_rejects_garbage() {
+        assert_eq!(exif_orientation_value(b"not exif"), None);
+        // Valid header but no orientation tag present.
+        let mut block = b"Exif\0\0II".to_vec();
+        block.extend_from_slice(&42u16.to_le_bytes());
+        block.extend_from_slice(&8u32.to_le_bytes());
+        block.extend_from_slice(&0u16.to_le_bytes()); // zero entries
+        assert_eq!(exif_orientation_value(&block), None);
+    }
+}
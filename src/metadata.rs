@@ -72,4 +72,210 @@ impl Orientation {
             Orientation::Rotate270FlipH => false,
         }
     }
+
+    /// Decomposes the orientation into `k` clockwise quarter-turns (`0..=3`)
+    /// followed by an optional horizontal flip. Every member of the dihedral
+    /// group of the square can be written this way, which is what makes
+    /// [`combine`](Self::combine) and [`inverse`](Self::inverse) cheap.
+    fn decompose(self) -> (u8, bool) {
+        match self {
+            Orientation::NoTransforms => (0, false),
+            Orientation::Rotate90 => (1, false),
+            Orientation::Rotate180 => (2, false),
+            Orientation::Rotate270 => (3, false),
+            Orientation::FlipHorizontal => (0, true),
+            Orientation::Rotate90FlipH => (1, true),
+            Orientation::FlipVertical => (2, true),
+            Orientation::Rotate270FlipH => (3, true),
+        }
+    }
+
+    /// Inverse of [`decompose`](Self::decompose).
+    fn compose((quarter_turns, flip): (u8, bool)) -> Self {
+        match (quarter_turns & 3, flip) {
+            (0, false) => Orientation::NoTransforms,
+            (1, false) => Orientation::Rotate90,
+            (2, false) => Orientation::Rotate180,
+            (3, false) => Orientation::Rotate270,
+            (0, true) => Orientation::FlipHorizontal,
+            (1, true) => Orientation::Rotate90FlipH,
+            (2, true) => Orientation::FlipVertical,
+            (3, true) => Orientation::Rotate270FlipH,
+            _ => unreachable!(),
+        }
+    }
+
+    /// Returns the single orientation equivalent to applying `self` and then
+    /// `other`, using the group composition of the eight symmetries.
+    ///
+    /// This lets callers fold a sequence of corrections into one pass, for
+    /// example composing "undo the camera orientation" with a user-requested
+    /// rotation.
+    pub fn combine(self, other: Orientation) -> Orientation {
+        let (k1, f1) = self.decompose();
+        let (k2, f2) = other.decompose();
+        // `self`'s own flip reverses the direction of `other`'s rotation:
+        // `F · R^k = R^-k · F`, so when `self` carries a flip, `other`'s
+        // quarter-turns are subtracted rather than added.
+        let k = if f1 {
+            (k1 + 4 - k2) & 3
+        } else {
+            (k1 + k2) & 3
+        };
+        Orientation::compose((k, f1 ^ f2))
+    }
+
+    /// Returns the orientation that undoes `self`, i.e. the unique `o` for which
+    /// `self.combine(o) == Orientation::NoTransforms`.
+    pub fn inverse(self) -> Orientation {
+        let (k, flip) = self.decompose();
+        // Reflections are their own inverse; rotations invert by negation.
+        if flip {
+            self
+        } else {
+            Orientation::compose(((4 - k) & 3, false))
+        }
+    }
+
+    /// Applies this orientation to a tightly packed pixel `buffer` of the given
+    /// dimensions, returning the dimensions of the result.
+    ///
+    /// The four orientations for which [`applies_in_place`](Self::applies_in_place)
+    /// is `true` rearrange the buffer without allocating a second copy of the
+    /// image; the rotating orientations use a single allocation and exchange the
+    /// width and height. `channels` is the number of bytes per pixel.
+    pub fn apply_to_buffer(
+        self,
+        buffer: &mut Vec<u8>,
+        width: u32,
+        height: u32,
+        channels: usize,
+    ) -> (u32, u32) {
+        let w = width as usize;
+        let h = height as usize;
+        let stride = w * channels;
+        match self {
+            Orientation::NoTransforms => {}
+            Orientation::FlipHorizontal => {
+                for row in buffer.chunks_exact_mut(stride) {
+                    reverse_pixels(row, channels);
+                }
+            }
+            Orientation::FlipVertical => {
+                let mut tmp = vec![0u8; stride];
+                for y in 0..h / 2 {
+                    let top = y * stride;
+                    let bottom = (h - 1 - y) * stride;
+                    tmp.copy_from_slice(&buffer[top..top + stride]);
+                    buffer.copy_within(bottom..bottom + stride, top);
+                    buffer[bottom..bottom + stride].copy_from_slice(&tmp);
+                }
+            }
+            Orientation::Rotate180 => {
+                let pixels = w * h;
+                for i in 0..pixels / 2 {
+                    let a = i * channels;
+                    let b = (pixels - 1 - i) * channels;
+                    for c in 0..channels {
+                        buffer.swap(a + c, b + c);
+                    }
+                }
+            }
+            // Rotations cannot be performed in place because they exchange the
+            // width and height, so they go through a single fresh allocation.
+            Orientation::Rotate90
+            | Orientation::Rotate270
+            | Orientation::Rotate90FlipH
+            | Orientation::Rotate270FlipH => {
+                let out_width = h;
+                let mut dst = vec![0u8; buffer.len()];
+                for y in 0..h {
+                    for x in 0..w {
+                        let (nx, ny) = match self {
+                            Orientation::Rotate90 => (h - 1 - y, x),
+                            Orientation::Rotate270 => (y, w - 1 - x),
+                            Orientation::Rotate90FlipH => (y, x),
+                            Orientation::Rotate270FlipH => (h - 1 - y, w - 1 - x),
+                            _ => unreachable!(),
+                        };
+                        let src_i = (y * w + x) * channels;
+                        let dst_i = (ny * out_width + nx) * channels;
+                        dst[dst_i..dst_i + channels]
+                            .copy_from_slice(&buffer[src_i..src_i + channels]);
+                    }
+                }
+                *buffer = dst;
+                return (height, width);
+            }
+        }
+        (width, height)
+    }
+}
+
+/// Reverses the order of the pixels within a single row in place.
+fn reverse_pixels(row: &mut [u8], channels: usize) {
+    let pixels = row.len() / channels;
+    for i in 0..pixels / 2 {
+        let a = i * channels;
+        let b = (pixels - 1 - i) * channels;
+        for c in 0..channels {
+            row.swap(a + c, b + c);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Orientation;
+
+    const ALL: [Orientation; 8] = [
+        Orientation::NoTransforms,
+        Orientation::Rotate90,
+        Orientation::Rotate180,
+        Orientation::Rotate270,
+        Orientation::FlipHorizontal,
+        Orientation::FlipVertical,
+        Orientation::Rotate90FlipH,
+        Orientation::Rotate270FlipH,
+    ];
+
+    #[test]
+    fn inverse_round_trips() {
+        for o in ALL {
+            assert_eq!(o.combine(o.inverse()), Orientation::NoTransforms);
+            assert_eq!(o.inverse().combine(o), Orientation::NoTransforms);
+        }
+    }
+
+    #[test]
+    fn combine_known_pairs() {
+        use Orientation::*;
+        assert_eq!(Rotate90.combine(Rotate90), Rotate180);
+        assert_eq!(Rotate90.combine(FlipHorizontal), Rotate90FlipH);
+        assert_eq!(FlipHorizontal.combine(Rotate90), Rotate270FlipH);
+        assert_eq!(FlipHorizontal.combine(FlipHorizontal), NoTransforms);
+        assert_eq!(NoTransforms.combine(Rotate270), Rotate270);
+    }
+
+    #[test]
+    fn combine_matches_sequential_application() {
+        // A 3×2 single-channel grid with distinct values, so every transform
+        // produces a different buffer.
+        let (width, height) = (3u32, 2u32);
+        let original: Vec<u8> = (0..(width * height) as u8).collect();
+        for first in ALL {
+            for second in ALL {
+                // Apply `first` then `second` in two passes.
+                let mut sequential = original.clone();
+                let (w, h) = first.apply_to_buffer(&mut sequential, width, height, 1);
+                let (w, h) = second.apply_to_buffer(&mut sequential, w, h, 1);
+                // Apply the single combined orientation in one pass.
+                let mut combined = original.clone();
+                let combined_dims =
+                    first.combine(second).apply_to_buffer(&mut combined, width, height, 1);
+                assert_eq!(combined_dims, (w, h), "dims for {first:?} then {second:?}");
+                assert_eq!(sequential, combined, "pixels for {first:?} then {second:?}");
+            }
+        }
+    }
 }
@@ -90,6 +90,28 @@ fn gif() {
     assert!(decoder.set_limits(allocation_limits()).is_err());
 }
 
+#[test]
+#[cfg(feature = "jpeg")]
+fn jpeg() {
+    use image::codecs::jpeg::JpegDecoder;
+
+    let image = test_image(ImageOutputFormat::Jpeg(80));
+    // sanity check that our image loads successfully without limits
+    assert!(load_from_memory_with_format(&image, ImageFormat::Jpeg).is_ok());
+    // check that the limits implementation is not overly restrictive
+    assert!(load_through_reader(&image, ImageFormat::Jpeg, permissive_limits()).is_ok());
+    // image::io::Reader
+    assert!(load_through_reader(&image, ImageFormat::Jpeg, width_height_limits()).is_err());
+    assert!(load_through_reader(&image, ImageFormat::Jpeg, allocation_limits()).is_err());
+    // JpegDecoder
+    let mut decoder = JpegDecoder::new(Cursor::new(&image)).unwrap();
+    assert!(decoder.set_limits(width_height_limits()).is_ok());
+    assert!(decoder.read_image(&mut [0; (WIDTH * HEIGHT * 3) as usize]).is_err());
+    let mut decoder = JpegDecoder::new(Cursor::new(&image)).unwrap();
+    assert!(decoder.set_limits(allocation_limits()).is_ok());
+    assert!(decoder.read_image(&mut [0; (WIDTH * HEIGHT * 3) as usize]).is_err());
+}
+
 #[test]
 #[cfg(feature = "png")]
 fn png() {